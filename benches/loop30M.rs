@@ -1,96 +1,60 @@
-use std::hint::black_box;
-use borsh::{BorshDeserialize, BorshSerialize};
-use bytemuck::{Pod, Zeroable};
-use criterion::{criterion_group, criterion_main, Criterion};
-
-// ---------- Structs ----------
-#[repr(C)]
-#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
-pub struct UserBorsh {
-    pub balance: u64,
-    pub nonce: u8,
-    pub padding: [u8; 7],
-}
-
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Zeroable, Pod)]
-pub struct UserBytemuck {
-    pub balance: u64,
-    pub nonce: u8,
-    pub padding: [u8; 7],
-}
-
-// ---------- Manual Deserialize ----------
-fn manual_deserialize(data: &[u8]) -> (u64, u8) {
-    let balance = u64::from_le_bytes(data[0..8].try_into().unwrap());
-    let nonce = data[8];
-    (balance, nonce)
-}
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use rust_deserializer_bench::backends::{BorshBackend, BytemuckBackend, ManualBackend};
+use rust_deserializer_bench::{Deserializer, SimpleUser, generate_simple_users};
 
 // Max iterations (30 million)
 const MAX_ITERS: usize = 30_000_000;
 
-// ---------- Bench: Borsh ----------
-fn bench_borsh(c: &mut Criterion) {
-    let user = UserBorsh {
-        balance: 1234567890123456789,
-        nonce: 42,
-        padding: [0; 7],
-    };
-    let bytes = borsh::to_vec(&user).unwrap();
-
-    c.bench_function("borsh_deserialize", |b| {
+// Repeatedly decodes the *same* single-record buffer `MAX_ITERS` times,
+// to amortize loop/measurement overhead down to the per-call decode
+// cost. Reuses the same `Deserializer` backends as
+// `benches/deserialize10kusers.rs` instead of re-implementing each
+// backend's parsing logic here.
+fn bench_repeated<B>(c: &mut Criterion, group_name: &str)
+where
+    B: Deserializer<SimpleUser, Buffer = Vec<u8>>,
+{
+    let users = generate_simple_users(1);
+    let buffer = B::prepare(&users);
+    let record_len = B::byte_len(&buffer);
+
+    let mut group = c.benchmark_group(group_name);
+
+    group.throughput(Throughput::Bytes((record_len * MAX_ITERS) as u64));
+    group.bench_function(B::name(), |b| {
         b.iter(|| {
-            let mut acc: u64 = 0;
+            let mut acc = 0u64;
             for _ in 0..MAX_ITERS {
-                let u = UserBorsh::try_from_slice(black_box(&bytes)).unwrap();
-                acc = acc.wrapping_add(u.balance ^ (u.nonce as u64));
+                acc = acc.wrapping_add(B::run(std::hint::black_box(&buffer), 1));
             }
-            black_box(acc);
+            std::hint::black_box(acc);
         })
     });
-}
 
-// ---------- Bench: Bytemuck ----------
-fn bench_bytemuck(c: &mut Criterion) {
-    let user = UserBytemuck {
-        balance: 1234567890123456789,
-        nonce: 42,
-        padding: [0; 7],
-    };
-    let bytes = bytemuck::bytes_of(&user).to_vec();
-
-    c.bench_function("bytemuck_from_bytes", |b| {
+    group.throughput(Throughput::Elements(MAX_ITERS as u64));
+    group.bench_function(format!("{}_elements", B::name()), |b| {
         b.iter(|| {
-            let mut acc: u64 = 0;
+            let mut acc = 0u64;
             for _ in 0..MAX_ITERS {
-                let u: &UserBytemuck = bytemuck::from_bytes(black_box(&bytes));
-                acc = acc.wrapping_add(u.balance ^ (u.nonce as u64));
+                acc = acc.wrapping_add(B::run(std::hint::black_box(&buffer), 1));
             }
-            black_box(acc);
+            std::hint::black_box(acc);
         })
     });
+
+    group.finish();
 }
 
-// ---------- Bench: Manual ----------
-fn bench_manual(c: &mut Criterion) {
-    let user = UserBytemuck {
-        balance: 1234567890123456789,
-        nonce: 42,
-        padding: [0; 7],
-    };
-    let bytes = bytemuck::bytes_of(&user).to_vec();
+fn bench_borsh(c: &mut Criterion) {
+    bench_repeated::<BorshBackend>(c, "deserialize_30m_borsh");
+}
 
-    c.bench_function("manual_from_slice", |b| {
-        b.iter(|| {
-            let mut acc: u64 = 0;
-            for _ in 0..MAX_ITERS {
-                let (bal, non) = manual_deserialize(black_box(&bytes));
-                acc = acc.wrapping_add(bal ^ (non as u64));
-            }
-            black_box(acc);
-        })
-    });
+fn bench_bytemuck(c: &mut Criterion) {
+    bench_repeated::<BytemuckBackend>(c, "deserialize_30m_bytemuck");
+}
+
+fn bench_manual(c: &mut Criterion) {
+    bench_repeated::<ManualBackend>(c, "deserialize_30m_manual");
 }
 
 // ---------- Criterion Main ----------
@@ -0,0 +1,253 @@
+//! `Deserializer` implementations for the fixed-layout record types
+//! (`AmmPool`, `SimpleUser`), benchmarked by `benches/solana10kbench.rs`,
+//! `benches/deserialize10kusers.rs`, and `benches/loop30M.rs`.
+
+use borsh::BorshDeserialize;
+
+use crate::{AmmPool, Checksum, Deserializer, SimpleUser};
+
+fn manual_amm_pool_total_supply(data: &[u8]) -> u64 {
+    // total_supply offset = 32*5 + 8*2 = 176
+    u64::from_le_bytes(data[176..184].try_into().unwrap())
+}
+
+fn manual_simple_user_checksum(data: &[u8]) -> u64 {
+    // balance offset = 0, nonce offset = 8
+    let balance = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    balance ^ (data[8] as u64)
+}
+
+pub struct BorshBackend;
+
+impl Deserializer<AmmPool> for BorshBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "borsh_amm_pool"
+    }
+
+    fn prepare(data: &[AmmPool]) -> Vec<u8> {
+        data.iter().flat_map(|p| borsh::to_vec(p).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut cursor = &bytes[..];
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let p: AmmPool = BorshDeserialize::deserialize(&mut cursor).unwrap();
+            acc = acc.wrapping_add(p.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<SimpleUser> for BorshBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "borsh_simple_user"
+    }
+
+    fn prepare(data: &[SimpleUser]) -> Vec<u8> {
+        data.iter().flat_map(|u| borsh::to_vec(u).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut cursor = &bytes[..];
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let u: SimpleUser = BorshDeserialize::deserialize(&mut cursor).unwrap();
+            acc = acc.wrapping_add(u.checksum());
+        }
+        acc
+    }
+}
+
+pub struct BytemuckBackend;
+
+impl Deserializer<AmmPool> for BytemuckBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "bytemuck_amm_pool"
+    }
+
+    fn prepare(data: &[AmmPool]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|p| bytemuck::bytes_of(p).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        let pool_size = std::mem::size_of::<AmmPool>();
+        for i in 0..count {
+            let start = i * pool_size;
+            let end = start + pool_size;
+            let p: &AmmPool = bytemuck::from_bytes(&bytes[start..end]);
+            acc = acc.wrapping_add(p.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<SimpleUser> for BytemuckBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "bytemuck_simple_user"
+    }
+
+    fn prepare(data: &[SimpleUser]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|u| bytemuck::bytes_of(u).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        let user_size = std::mem::size_of::<SimpleUser>();
+        for i in 0..count {
+            let start = i * user_size;
+            let end = start + user_size;
+            let u: &SimpleUser = bytemuck::from_bytes(&bytes[start..end]);
+            acc = acc.wrapping_add(u.checksum());
+        }
+        acc
+    }
+}
+
+/// Like [`BytemuckBackend`], but casts the whole buffer to `&[R]` once
+/// and iterates the typed slice, instead of indexing and calling
+/// `bytemuck::from_bytes` per element.
+pub struct BytemuckCastSliceBackend;
+
+impl Deserializer<AmmPool> for BytemuckCastSliceBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "bytemuck_cast_slice_amm_pool"
+    }
+
+    fn prepare(data: &[AmmPool]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|p| bytemuck::bytes_of(p).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let pools: &[AmmPool] = bytemuck::cast_slice(bytes);
+        let mut acc = 0u64;
+        for p in &pools[..count] {
+            acc = acc.wrapping_add(p.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<SimpleUser> for BytemuckCastSliceBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "bytemuck_cast_slice_simple_user"
+    }
+
+    fn prepare(data: &[SimpleUser]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|u| bytemuck::bytes_of(u).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let users: &[SimpleUser] = bytemuck::cast_slice(bytes);
+        let mut acc = 0u64;
+        for u in &users[..count] {
+            acc = acc.wrapping_add(u.checksum());
+        }
+        acc
+    }
+}
+
+pub struct ManualBackend;
+
+impl Deserializer<AmmPool> for ManualBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_amm_pool"
+    }
+
+    fn prepare(data: &[AmmPool]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|p| bytemuck::bytes_of(p).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        let pool_size = std::mem::size_of::<AmmPool>();
+        let mut offset = 0;
+        for _ in 0..count {
+            acc = acc.wrapping_add(manual_amm_pool_total_supply(&bytes[offset..]));
+            offset += pool_size;
+        }
+        acc
+    }
+}
+
+impl Deserializer<SimpleUser> for ManualBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_simple_user"
+    }
+
+    fn prepare(data: &[SimpleUser]) -> Vec<u8> {
+        data.iter()
+            .flat_map(|u| bytemuck::bytes_of(u).iter().copied())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        let user_size = std::mem::size_of::<SimpleUser>();
+        let mut offset = 0;
+        for _ in 0..count {
+            acc = acc.wrapping_add(manual_simple_user_checksum(&bytes[offset..]));
+            offset += user_size;
+        }
+        acc
+    }
+}
@@ -0,0 +1,316 @@
+//! `Deserializer` implementations for the dynamic record types
+//! (`ComplexUser`, `DynamicAmmPool`), benchmarked by
+//! `benches/deserializeVariables.rs` and `benches/variableSolana.rs`.
+//!
+//! Unlike the fixed-layout backends in [`crate::backends`], these
+//! records carry variable-length `String`/`Vec<u64>` fields, so the
+//! Borsh and manual decoders walk the buffer field-by-field rather than
+//! casting or indexing by a constant stride; only rkyv casts, one
+//! buffer per record.
+
+use borsh::BorshDeserialize;
+use rkyv::AlignedVec;
+
+use crate::manual_checked::decode_user;
+use crate::{Checksum, ComplexUser, Deserializer, DynamicAmmPool};
+
+pub struct BorshBackend;
+
+impl Deserializer<ComplexUser> for BorshBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "borsh_complex_user"
+    }
+
+    fn prepare(data: &[ComplexUser]) -> Vec<u8> {
+        data.iter().flat_map(|u| borsh::to_vec(u).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut cursor = &bytes[..];
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let u = ComplexUser::deserialize(&mut cursor).unwrap();
+            acc = acc.wrapping_add(u.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<DynamicAmmPool> for BorshBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "borsh_amm_pool_dynamic"
+    }
+
+    fn prepare(data: &[DynamicAmmPool]) -> Vec<u8> {
+        data.iter().flat_map(|p| borsh::to_vec(p).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut cursor = &bytes[..];
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let p = DynamicAmmPool::deserialize(&mut cursor).unwrap();
+            acc = acc.wrapping_add(p.checksum());
+        }
+        acc
+    }
+}
+
+/// Safe sequential parse. For `ComplexUser` it walks each field in
+/// encoding order, fully reconstructing the record (matches what Borsh
+/// does, minus Borsh's own framing). For `DynamicAmmPool` it reads only
+/// `total_supply` and skips every other field, `positions` included.
+pub struct ManualBackend;
+
+impl Deserializer<ComplexUser> for ManualBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_complex_user"
+    }
+
+    fn prepare(data: &[ComplexUser]) -> Vec<u8> {
+        data.iter().flat_map(|u| borsh::to_vec(u).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut cursor = &bytes[..];
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let balance = u64::from_le_bytes(cursor[..8].try_into().unwrap());
+            cursor = &cursor[8..];
+
+            let nonce = cursor[0];
+            cursor = &cursor[1..];
+
+            let mut padding = [0u8; 7];
+            padding.copy_from_slice(&cursor[..7]);
+            cursor = &cursor[7..];
+
+            // name (Borsh encodes String as u32 length + UTF-8 bytes)
+            let name_len = u32::from_le_bytes(cursor[..4].try_into().unwrap()) as usize;
+            cursor = &cursor[4..];
+            let name = String::from_utf8(cursor[..name_len].to_vec()).unwrap();
+            cursor = &cursor[name_len..];
+
+            // transactions (Vec<u64>)
+            let tx_len = u32::from_le_bytes(cursor[..4].try_into().unwrap()) as usize;
+            cursor = &cursor[4..];
+            let mut transactions = Vec::with_capacity(tx_len);
+            for _ in 0..tx_len {
+                let val = u64::from_le_bytes(cursor[..8].try_into().unwrap());
+                cursor = &cursor[8..];
+                transactions.push(val);
+            }
+
+            let user = ComplexUser { balance, nonce, padding, name, transactions };
+            acc = acc.wrapping_add(user.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<DynamicAmmPool> for ManualBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_amm_pool_dynamic"
+    }
+
+    fn prepare(data: &[DynamicAmmPool]) -> Vec<u8> {
+        data.iter().flat_map(|p| borsh::to_vec(p).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut offset = 0;
+        let mut acc = 0u64;
+        for _ in 0..count {
+            offset += 32 * 3; // token_a_mint, token_b_mint, pool_mint
+            offset += 8 * 2; // reserve_a, reserve_b
+
+            let total_supply = u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+            offset += 8; // total_supply
+            offset += 2; // fee_rate
+
+            let positions_len =
+                u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as usize;
+            offset += 4 + positions_len * 8; // skip positions
+
+            acc = acc.wrapping_add(total_supply);
+        }
+        acc
+    }
+}
+
+/// Unsafe fast path: reads fixed-width fields through raw pointers with
+/// no bounds check and skips UTF-8 validation, measured
+/// against [`ManualCheckedBackend`] to quantify what validation costs.
+pub struct ManualOptimizedBackend;
+
+impl Deserializer<ComplexUser> for ManualOptimizedBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_optimized_complex_user"
+    }
+
+    fn prepare(data: &[ComplexUser]) -> Vec<u8> {
+        data.iter().flat_map(|u| borsh::to_vec(u).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut offset = 0;
+        let mut acc = 0u64;
+
+        for _ in 0..count {
+            let balance =
+                u64::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 8]) });
+            offset += 8;
+
+            let nonce = bytes[offset];
+            offset += 1;
+
+            offset += 7; // padding, skipped entirely
+
+            let name_len =
+                u32::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 4]) })
+                    as usize;
+            offset += 4;
+            let name =
+                unsafe { String::from_utf8_unchecked(bytes[offset..offset + name_len].to_vec()) };
+            offset += name_len;
+
+            let tx_len =
+                u32::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 4]) })
+                    as usize;
+            offset += 4;
+            let mut transactions = Vec::with_capacity(tx_len);
+            for _ in 0..tx_len {
+                let val =
+                    u64::from_le_bytes(unsafe { *(bytes.as_ptr().add(offset) as *const [u8; 8]) });
+                offset += 8;
+                transactions.push(val);
+            }
+
+            let user = ComplexUser { balance, nonce, padding: [0; 7], name, transactions };
+            acc = acc.wrapping_add(user.checksum());
+        }
+
+        acc
+    }
+}
+
+/// Bounds-checked parse via [`decode_user`], replicating Borsh's safety
+/// discipline; benchmarked against [`ManualOptimizedBackend`] to
+/// quantify what that validation costs.
+pub struct ManualCheckedBackend;
+
+impl Deserializer<ComplexUser> for ManualCheckedBackend {
+    type Buffer = Vec<u8>;
+
+    fn name() -> &'static str {
+        "manual_checked_complex_user"
+    }
+
+    fn prepare(data: &[ComplexUser]) -> Vec<u8> {
+        data.iter().flat_map(|u| borsh::to_vec(u).unwrap()).collect()
+    }
+
+    fn byte_len(buffer: &Vec<u8>) -> usize {
+        buffer.len()
+    }
+
+    fn run(bytes: &Vec<u8>, count: usize) -> u64 {
+        let mut offset = 0;
+        let mut acc = 0u64;
+        for _ in 0..count {
+            let user = decode_user(bytes, &mut offset).unwrap();
+            acc = acc.wrapping_add(user.checksum());
+        }
+        acc
+    }
+}
+
+/// Zero-copy deserialization via rkyv. Each record gets its own buffer,
+/// since rkyv roots are found relative to the end of the buffer that
+/// holds them and can't be concatenated the way length-prefixed Borsh
+/// records can.
+pub struct RkyvBackend;
+
+impl Deserializer<ComplexUser> for RkyvBackend {
+    type Buffer = Vec<AlignedVec>;
+
+    fn name() -> &'static str {
+        "rkyv_complex_user"
+    }
+
+    fn prepare(data: &[ComplexUser]) -> Vec<AlignedVec> {
+        data.iter()
+            .map(|u| rkyv::to_bytes::<_, 256>(u).unwrap())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<AlignedVec>) -> usize {
+        buffer.iter().map(|buf| buf.len()).sum()
+    }
+
+    fn run(buffer: &Vec<AlignedVec>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        for buf in &buffer[..count] {
+            let archived = unsafe { rkyv::archived_root::<ComplexUser>(buf) };
+            acc = acc.wrapping_add(archived.checksum());
+        }
+        acc
+    }
+}
+
+impl Deserializer<DynamicAmmPool> for RkyvBackend {
+    type Buffer = Vec<AlignedVec>;
+
+    fn name() -> &'static str {
+        "rkyv_amm_pool_dynamic"
+    }
+
+    fn prepare(data: &[DynamicAmmPool]) -> Vec<AlignedVec> {
+        data.iter()
+            .map(|p| rkyv::to_bytes::<_, 256>(p).unwrap())
+            .collect()
+    }
+
+    fn byte_len(buffer: &Vec<AlignedVec>) -> usize {
+        buffer.iter().map(|buf| buf.len()).sum()
+    }
+
+    fn run(buffer: &Vec<AlignedVec>, count: usize) -> u64 {
+        let mut acc = 0u64;
+        for buf in &buffer[..count] {
+            let archived = unsafe { rkyv::archived_root::<DynamicAmmPool>(buf) };
+            acc = acc.wrapping_add(archived.checksum());
+        }
+        acc
+    }
+}
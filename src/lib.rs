@@ -0,0 +1,88 @@
+//! Shared types and benchmarking harness for `rust-deserializer-bench`.
+//!
+//! Each file under `benches/` compares some of Borsh, bytemuck, rkyv,
+//! and hand-rolled parsing against the same data. The [`Deserializer`]
+//! trait and the [`bench_all!`] macro let a backend be registered once
+//! for whichever record type(s) it supports and benchmarked
+//! automatically, instead of hand-editing every bench file.
+
+pub mod backends;
+pub mod dynamic_backends;
+pub mod manual_checked;
+mod types;
+mod workload;
+
+pub use types::{
+    AmmPool, ComplexUser, DynamicAmmPool, SimpleUser, generate_amm_pools, generate_complex_users,
+    generate_dynamic_amm_pools, generate_simple_users,
+};
+pub use workload::{LengthDist, WorkloadConfig};
+
+/// Reduces a deserialized record to a single `u64`, so a benchmark loop
+/// can fold over many records of any shape into one value the
+/// optimizer can't discard.
+pub trait Checksum {
+    fn checksum(&self) -> u64;
+}
+
+/// A deserialization strategy benchmarked against `R` records.
+///
+/// Implementors own both their encoding (`prepare`) and decoding
+/// (`run`), so [`bench_all!`] only needs a list of backends to register
+/// one Criterion function per backend. `Buffer` is whatever shape the
+/// backend's own encoding produces — a flat `Vec<u8>` for
+/// length-prefixed or fixed-layout formats, or one buffer per record
+/// for formats like rkyv whose roots can't be concatenated.
+pub trait Deserializer<R> {
+    /// The prepared, backend-specific encoding of a `&[R]`.
+    type Buffer;
+
+    /// Criterion benchmark id for this backend.
+    fn name() -> &'static str;
+
+    /// Encode `data` into the buffer this backend will deserialize.
+    fn prepare(data: &[R]) -> Self::Buffer;
+
+    /// Number of bytes `buffer` occupies, for `Throughput::Bytes`.
+    fn byte_len(buffer: &Self::Buffer) -> usize;
+
+    /// Deserialize `count` elements from `buffer`, returning the
+    /// wrapping sum of [`Checksum::checksum`] so the loop can't be
+    /// optimized away.
+    fn run(buffer: &Self::Buffer, count: usize) -> u64;
+}
+
+/// Registers one `group.bench_function` per backend, each preparing its
+/// own buffer from `data` and measuring [`Deserializer::run`]. The
+/// trailing `bytes`/`elements` tag selects which `Throughput` the group
+/// reports, computed from each backend's own [`Deserializer::byte_len`]
+/// (backends can disagree on encoded size, e.g. rkyv's per-record
+/// alignment padding vs Borsh's tight packing).
+#[macro_export]
+macro_rules! bench_all {
+    ($group:expr, [$($backend:ty),+ $(,)?], $data:expr, bytes) => {
+        $crate::bench_all!(@run $group, [$($backend),+], $data,
+            |len: usize, _count: usize| criterion::Throughput::Bytes(len as u64));
+    };
+    ($group:expr, [$($backend:ty),+ $(,)?], $data:expr, elements) => {
+        $crate::bench_all!(@run $group, [$($backend),+], $data,
+            |_len: usize, count: usize| criterion::Throughput::Elements(count as u64));
+    };
+    (@run $group:expr, [$($backend:ty),+ $(,)?], $data:expr, $throughput:expr) => {
+        $(
+            {
+                let buffer = <$backend as $crate::Deserializer<_>>::prepare($data);
+                let count = $data.len();
+                $group.throughput(($throughput)(
+                    <$backend as $crate::Deserializer<_>>::byte_len(&buffer),
+                    count,
+                ));
+                $group.bench_function(<$backend as $crate::Deserializer<_>>::name(), |b| {
+                    b.iter(|| {
+                        std::hint::black_box(<$backend as $crate::Deserializer<_>>::run(&buffer, count));
+                    })
+                });
+            }
+        )+
+    };
+}
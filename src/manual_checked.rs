@@ -0,0 +1,147 @@
+//! A bounds-checked manual parser for the complex `ComplexUser` record
+//! (`balance`, `nonce`, `padding`, `name`, `transactions`), used by
+//! [`crate::dynamic_backends::ManualCheckedBackend`] to benchmark
+//! validation overhead against the unsafe fast path in
+//! [`crate::dynamic_backends::ManualOptimizedBackend`].
+
+use crate::Checksum;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Not enough bytes remained for the field being read.
+    UnexpectedEof,
+    /// A length prefix (`name_len`/`tx_len`) claimed more data than
+    /// remains in the buffer.
+    LengthOverflow,
+    /// `name` bytes were not valid UTF-8.
+    InvalidUtf8,
+}
+
+#[derive(Debug)]
+pub struct DecodedUser {
+    pub balance: u64,
+    pub nonce: u8,
+    pub padding: [u8; 7],
+    pub name: String,
+    pub transactions: Vec<u64>,
+}
+
+impl Checksum for DecodedUser {
+    fn checksum(&self) -> u64 {
+        self.balance ^ (self.nonce as u64)
+    }
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], offset: usize, len: usize) -> Result<&'a [u8], DecodeError> {
+    bytes.get(offset..offset + len).ok_or(DecodeError::UnexpectedEof)
+}
+
+/// Decodes one `DecodedUser` starting at `*offset`, advancing `*offset`
+/// past it on success. Every field read is bounds-checked against
+/// `bytes.len()` first, and `name` goes through `str::from_utf8` rather
+/// than the unchecked variant.
+pub fn decode_user(bytes: &[u8], offset: &mut usize) -> Result<DecodedUser, DecodeError> {
+    let balance = u64::from_le_bytes(read_bytes(bytes, *offset, 8)?.try_into().unwrap());
+    *offset += 8;
+
+    let nonce = read_bytes(bytes, *offset, 1)?[0];
+    *offset += 1;
+
+    let mut padding = [0u8; 7];
+    padding.copy_from_slice(read_bytes(bytes, *offset, 7)?);
+    *offset += 7;
+
+    let name_len = u32::from_le_bytes(read_bytes(bytes, *offset, 4)?.try_into().unwrap()) as usize;
+    *offset += 4;
+    if name_len > bytes.len().saturating_sub(*offset) {
+        return Err(DecodeError::LengthOverflow);
+    }
+    let name = String::from_utf8(read_bytes(bytes, *offset, name_len)?.to_vec())
+        .map_err(|_| DecodeError::InvalidUtf8)?;
+    *offset += name_len;
+
+    let tx_len = u32::from_le_bytes(read_bytes(bytes, *offset, 4)?.try_into().unwrap()) as usize;
+    *offset += 4;
+    if tx_len > bytes.len().saturating_sub(*offset) / 8 {
+        return Err(DecodeError::LengthOverflow);
+    }
+    let mut transactions = Vec::with_capacity(tx_len);
+    for _ in 0..tx_len {
+        let val = u64::from_le_bytes(read_bytes(bytes, *offset, 8)?.try_into().unwrap());
+        *offset += 8;
+        transactions.push(val);
+    }
+
+    Ok(DecodedUser {
+        balance,
+        nonce,
+        padding,
+        name,
+        transactions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_user_bytes() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&42u64.to_le_bytes());
+        bytes.push(7);
+        bytes.extend_from_slice(&[0u8; 7]);
+        let name = b"alice";
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name);
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&100u64.to_le_bytes());
+        bytes.extend_from_slice(&200u64.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn decodes_valid_input() {
+        let bytes = valid_user_bytes();
+        let mut offset = 0;
+        let user = decode_user(&bytes, &mut offset).unwrap();
+        assert_eq!(user.balance, 42);
+        assert_eq!(user.nonce, 7);
+        assert_eq!(user.name, "alice");
+        assert_eq!(user.transactions, vec![100, 200]);
+        assert_eq!(offset, bytes.len());
+    }
+
+    #[test]
+    fn rejects_truncated_balance() {
+        let bytes = [0u8; 4]; // fewer than the 8 bytes `balance` needs
+        let mut offset = 0;
+        assert_eq!(
+            decode_user(&bytes, &mut offset),
+            Err(DecodeError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn rejects_oversized_name_len() {
+        let mut bytes = valid_user_bytes();
+        // name_len lives right after balance (8) + nonce (1) + padding (7).
+        bytes[16..20].copy_from_slice(&u32::MAX.to_le_bytes());
+        let mut offset = 0;
+        assert_eq!(
+            decode_user(&bytes, &mut offset),
+            Err(DecodeError::LengthOverflow)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let mut bytes = valid_user_bytes();
+        // name starts right after the name_len prefix, at offset 20.
+        bytes[20] = 0xFF;
+        let mut offset = 0;
+        assert_eq!(
+            decode_user(&bytes, &mut offset),
+            Err(DecodeError::InvalidUtf8)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rkyv::{Archive, Serialize as RkyvSerialize};
+
+use crate::workload::WorkloadConfig;
+use crate::Checksum;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod, BorshSerialize, BorshDeserialize)]
+pub struct AmmPool {
+    pub token_a_mint: [u8; 32],
+    pub token_b_mint: [u8; 32],
+    pub token_a_vault: [u8; 32],
+    pub token_b_vault: [u8; 32],
+    pub pool_mint: [u8; 32],
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub total_supply: u64,
+    pub fee_rate: u16,
+    pub padding: [u8; 6],
+}
+
+impl Checksum for AmmPool {
+    fn checksum(&self) -> u64 {
+        self.total_supply
+    }
+}
+
+/// Fixed-layout user record (no variable-length fields), shared by the
+/// simple 16-byte-record benches (`deserialize10kusers`, `loop30M`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod, BorshSerialize, BorshDeserialize)]
+pub struct SimpleUser {
+    pub balance: u64,
+    pub nonce: u8,
+    pub padding: [u8; 7],
+}
+
+impl Checksum for SimpleUser {
+    fn checksum(&self) -> u64 {
+        self.balance ^ (self.nonce as u64)
+    }
+}
+
+/// Dynamic user record carrying variable-length `name`/`transactions`
+/// fields, used by the complex-record benches (`deserializeVariables`).
+#[derive(Debug, BorshSerialize, BorshDeserialize, Archive, RkyvSerialize)]
+pub struct ComplexUser {
+    pub balance: u64,
+    pub nonce: u8,
+    pub padding: [u8; 7],
+    pub name: String,
+    pub transactions: Vec<u64>,
+}
+
+impl Checksum for ComplexUser {
+    fn checksum(&self) -> u64 {
+        self.balance ^ (self.nonce as u64)
+    }
+}
+
+impl Checksum for ArchivedComplexUser {
+    fn checksum(&self) -> u64 {
+        self.balance ^ (self.nonce as u64)
+    }
+}
+
+/// Dynamic AMM pool record carrying a variable-length `positions` field,
+/// used by the dynamic-pool benches (`variableSolana`).
+#[derive(Debug, BorshSerialize, BorshDeserialize, Archive, RkyvSerialize)]
+pub struct DynamicAmmPool {
+    pub token_a_mint: [u8; 32],
+    pub token_b_mint: [u8; 32],
+    pub pool_mint: [u8; 32],
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub total_supply: u64,
+    pub fee_rate: u16,
+    pub positions: Vec<u64>,
+}
+
+impl Checksum for DynamicAmmPool {
+    fn checksum(&self) -> u64 {
+        self.total_supply
+    }
+}
+
+impl Checksum for ArchivedDynamicAmmPool {
+    fn checksum(&self) -> u64 {
+        self.total_supply
+    }
+}
+
+fn random_pubkey(rng: &mut StdRng) -> [u8; 32] {
+    let mut arr = [0u8; 32];
+    rng.fill(&mut arr);
+    arr
+}
+
+fn random_string(rng: &mut StdRng, len: usize) -> String {
+    (0..len)
+        .map(|_| rng.random_range(b'a'..=b'z') as char)
+        .collect()
+}
+
+pub fn generate_amm_pools(n: usize) -> Vec<AmmPool> {
+    let mut rng = StdRng::seed_from_u64(43);
+    (0..n)
+        .map(|_| AmmPool {
+            token_a_mint: random_pubkey(&mut rng),
+            token_b_mint: random_pubkey(&mut rng),
+            token_a_vault: random_pubkey(&mut rng),
+            token_b_vault: random_pubkey(&mut rng),
+            pool_mint: random_pubkey(&mut rng),
+            reserve_a: rng.random(),
+            reserve_b: rng.random(),
+            total_supply: rng.random(),
+            fee_rate: rng.random(),
+            padding: [0; 6],
+        })
+        .collect()
+}
+
+pub fn generate_simple_users(n: usize) -> Vec<SimpleUser> {
+    (0..n)
+        .map(|i| SimpleUser {
+            balance: i as u64,
+            nonce: (i % 256) as u8,
+            padding: [0; 7],
+        })
+        .collect()
+}
+
+pub fn generate_complex_users(config: &WorkloadConfig) -> Vec<ComplexUser> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    (0..config.count)
+        .map(|i| {
+            let name_len = config.name_len.sample(&mut rng);
+            let name = random_string(&mut rng, name_len);
+
+            let tx_count = config.positions_len.sample(&mut rng);
+            let transactions: Vec<u64> = (0..tx_count).map(|_| rng.random_range(1..1000)).collect();
+
+            ComplexUser {
+                balance: i as u64,
+                nonce: (i % 256) as u8,
+                padding: [0; 7],
+                name,
+                transactions,
+            }
+        })
+        .collect()
+}
+
+pub fn generate_dynamic_amm_pools(config: &WorkloadConfig) -> Vec<DynamicAmmPool> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    (0..config.count)
+        .map(|_| {
+            let positions_len = config.positions_len.sample(&mut rng);
+            let positions = (0..positions_len).map(|_| rng.random_range(1..1000)).collect();
+            DynamicAmmPool {
+                token_a_mint: random_pubkey(&mut rng),
+                token_b_mint: random_pubkey(&mut rng),
+                pool_mint: random_pubkey(&mut rng),
+                reserve_a: rng.random(),
+                reserve_b: rng.random(),
+                total_supply: rng.random(),
+                fee_rate: rng.random(),
+                positions,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,95 @@
+use rand::Rng;
+use rand::rngs::StdRng;
+
+/// Distribution over variable-length collection sizes used when
+/// generating benchmark workloads (e.g. `Vec<u64>` or `String` lengths).
+#[derive(Clone, Copy, Debug)]
+pub enum LengthDist {
+    /// Uniformly sampled from `[min, max)`, same as the old hardcoded
+    /// `rng.random_range(..)` calls.
+    Uniform { min: usize, max: usize },
+    /// Heavy-tailed: most draws are small, with a long but thin tail.
+    /// Models e.g. a handful of pools with somewhat more positions than
+    /// the rest, with the given `mean`.
+    Geometric { mean: f64 },
+    /// Genuinely heavy-tailed: log-normal draws, so most lengths cluster
+    /// around `median` but a non-negligible fraction land orders of
+    /// magnitude out in the tail. `sigma` is the standard deviation of
+    /// the underlying normal distribution (in log-space) and controls
+    /// how fat that tail is. Models a handful of pools with thousands
+    /// of positions among many with just a few.
+    LogNormal { median: f64, sigma: f64 },
+    /// Always the same length.
+    Fixed(usize),
+}
+
+/// Samples a standard normal variate via the Box-Muller transform.
+fn standard_normal(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.random::<f64>().max(f64::MIN_POSITIVE); // avoid ln(0.0)
+    let u2: f64 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+impl LengthDist {
+    pub fn sample(&self, rng: &mut StdRng) -> usize {
+        match *self {
+            LengthDist::Uniform { min, max } => rng.random_range(min..max),
+            LengthDist::Geometric { mean } => {
+                // Inverse-transform sampling: for success probability
+                // p = 1 / (1 + mean), P(X >= k) = (1 - p)^k.
+                let p = 1.0 / (1.0 + mean);
+                let u: f64 = rng.random();
+                ((1.0 - u).ln() / (1.0 - p).ln()).floor().max(0.0) as usize
+            }
+            LengthDist::LogNormal { median, sigma } => {
+                let z = standard_normal(rng);
+                (median.ln() + sigma * z).exp().round().max(0.0) as usize
+            }
+            LengthDist::Fixed(n) => n,
+        }
+    }
+}
+
+/// Parameters for generating a synthetic benchmark workload: how many
+/// items to produce, and the length distributions driving their
+/// variable-size fields.
+#[derive(Clone, Copy, Debug)]
+pub struct WorkloadConfig {
+    pub seed: u64,
+    pub count: usize,
+    /// Length distribution for `Vec<u64>` fields (`positions`, `transactions`).
+    pub positions_len: LengthDist,
+    /// Length distribution for `String` fields (`name`).
+    pub name_len: LengthDist,
+}
+
+impl WorkloadConfig {
+    /// Small, uniformly distributed collections — the historical default.
+    pub fn small_uniform(count: usize) -> Self {
+        Self {
+            seed: 42,
+            count,
+            positions_len: LengthDist::Uniform { min: 1, max: 10 },
+            name_len: LengthDist::Uniform { min: 5, max: 20 },
+        }
+    }
+
+    /// A few items with very large collections, most with small ones.
+    ///
+    /// `positions_len` uses [`LengthDist::LogNormal`] rather than
+    /// [`LengthDist::Geometric`]: a geometric draw with a mean of even a
+    /// few dozen has essentially zero chance of ever landing in the
+    /// thousands (P(X >= 1000) with mean 50 is about 2.5e-9), so it
+    /// can't produce the "a few pools with thousands of positions"
+    /// scenario this variant exists to exercise. The log-normal below
+    /// has a median of 5 but a long enough tail that roughly 0.4% of
+    /// 10,000 draws land at 1000+.
+    pub fn heavy_tailed(count: usize) -> Self {
+        Self {
+            seed: 42,
+            count,
+            positions_len: LengthDist::LogNormal { median: 5.0, sigma: 2.0 },
+            name_len: LengthDist::Geometric { mean: 12.0 },
+        }
+    }
+}